@@ -0,0 +1,951 @@
+//! C-ABI bindings for non-Rust clients (Python trading bots, Node services) that want
+//! to reuse this crate's bit-packing and EIP-712 encoding instead of reimplementing it.
+//!
+//! This module is the `cdylib` entry point (see `crate-type = ["cdylib", "rlib"]` in
+//! Cargo.toml). Every fallible function follows the same convention: it writes a
+//! `VertexFfiError` into the `out_error` out-param (when non-null), and the `*_new` and
+//! `*_to_signed_json` functions also return a pointer that is null on failure. The
+//! error code tells a null pointer apart from bad UTF-8, bad hex, invalid input, a
+//! serialization failure, a caught panic or an interior NUL. Every handle returned here
+//! has a matching `*_free` that must be called exactly once.
+
+use crate::vertex_utils::eip712_domain::{eip712_digest, vertex_domain};
+use crate::vertex_utils::eip712_structs::{
+    from_bytes32, to_bytes32, Cancellation, OrderType, WithdrawCollateral,
+};
+use crate::vertex_utils::order_builder::OrderBuilder;
+use ethers::types::transaction::eip712::Eip712;
+use ethers::types::{Bytes, H160};
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexFfiError {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    InvalidHex = 3,
+    InvalidInput = 4,
+    SerializeFailed = 5,
+    /// A panic was caught at the FFI boundary (e.g. non-UTF-8 bytes decoded back out of
+    /// a `sender` in `vertex_from_bytes32`). Recorded instead of unwinding
+    /// across `extern "C"`, which would be undefined behavior.
+    PanicAtBoundary = 6,
+    /// A string we tried to hand back to the caller as a `CString` contained an embedded
+    /// NUL byte, which isn't representable as a NUL-terminated C string. Distinct from
+    /// `InvalidUtf8`: the bytes were valid UTF-8, just not valid `CString` content.
+    InteriorNul = 7,
+}
+
+unsafe fn write_error(out_error: *mut VertexFfiError, error: VertexFfiError) {
+    if !out_error.is_null() {
+        *out_error = error;
+    }
+}
+
+unsafe fn read_str<'a>(s: *const c_char) -> Result<&'a str, VertexFfiError> {
+    if s.is_null() {
+        return Err(VertexFfiError::NullPointer);
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| VertexFfiError::InvalidUtf8)
+}
+
+unsafe fn read_address(address_hex: *const c_char) -> Result<H160, VertexFfiError> {
+    read_str(address_hex)?
+        .trim_start_matches("0x")
+        .parse::<H160>()
+        .map_err(|_| VertexFfiError::InvalidHex)
+}
+
+/// `to_bytes32` panics if `name` is over 12 bytes (it indexes a fixed `[u8; 12]`), so
+/// validate the length ourselves before handing it off.
+unsafe fn read_subaccount_name<'a>(s: *const c_char) -> Result<&'a str, VertexFfiError> {
+    let name = read_str(s)?;
+    if name.len() > 12 {
+        return Err(VertexFfiError::InvalidInput);
+    }
+    Ok(name)
+}
+
+/// Maps the C-ABI `order_type` convention (`0..=3`, matching `OrderType`'s declaration
+/// order) onto `OrderType`, since an enum with field-less variants isn't FFI-safe on its
+/// own.
+fn read_order_type(order_type: i32) -> Result<OrderType, VertexFfiError> {
+    match order_type {
+        0 => Ok(OrderType::Default),
+        1 => Ok(OrderType::ImmediateOrCancel),
+        2 => Ok(OrderType::FillOrKill),
+        3 => Ok(OrderType::PostOnly),
+        _ => Err(VertexFfiError::InvalidInput),
+    }
+}
+
+/// Opaque handle to a built, not-yet-signed `Order`.
+pub struct VertexOrderHandle(crate::vertex_utils::eip712_structs::Order);
+
+/// Builds an `Order` from primitive fields, encoding `sender` as `to_bytes32(address,
+/// subaccount_name)`. `order_type` is `0` = default, `1` = IOC, `2` = FOK, `3` =
+/// post-only (matching `OrderType`'s declaration order); `nonce_rand` is the caller's
+/// choice of the nonce's low 20 bits, so two orders built in the same `recv_time` don't
+/// collide on the exact same nonce.
+///
+/// # Safety
+/// `sender_address_hex` and `subaccount_name` must be valid, NUL-terminated C strings.
+/// `out_error` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_order_new(
+    sender_address_hex: *const c_char,
+    subaccount_name: *const c_char,
+    price_x18: i128,
+    amount: i128,
+    order_type: i32,
+    expiration: u64,
+    recv_time: u64,
+    nonce_rand: u64,
+    reduce_only: bool,
+    is_trigger: bool,
+    out_error: *mut VertexFfiError,
+) -> *mut VertexOrderHandle {
+    let result = (|| -> Result<VertexOrderHandle, VertexFfiError> {
+        let address = read_address(sender_address_hex)?;
+        let name = read_subaccount_name(subaccount_name)?;
+        let sender = to_bytes32(address, name);
+        let order_type = read_order_type(order_type)?;
+        let order = OrderBuilder::new(sender, price_x18, amount)
+            .order_type(order_type)
+            .expiration(expiration)
+            .recv_time(recv_time)
+            .nonce_rand(nonce_rand)
+            .reduce_only(reduce_only)
+            .is_trigger(is_trigger)
+            .build()
+            .map_err(|_| VertexFfiError::InvalidInput)?;
+        Ok(VertexOrderHandle(order))
+    })();
+
+    match result {
+        Ok(handle) => {
+            write_error(out_error, VertexFfiError::Ok);
+            Box::into_raw(Box::new(handle))
+        }
+        Err(e) => {
+            write_error(out_error, e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// # Safety
+/// `handle` must either be null or a pointer previously returned by `vertex_order_new`
+/// that hasn't already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_order_free(handle: *mut VertexOrderHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Computes the EIP-712 digest a built order would need signed, against the Vertex
+/// domain for `chain_id`/`verifying_contract_hex`, writing it into the 32-byte buffer at
+/// `out_digest`. This is the one canonical hash this crate computes; callers sign it
+/// however they like (a local key, a hardware wallet, a round-trip to an MPC/custody
+/// service) and feed the result back into `vertex_order_to_signed_json` as
+/// `signature_bytes`, instead of reimplementing the bit-packing and EIP-712 hashing
+/// outside Rust.
+///
+/// # Safety
+/// `handle` must be a live pointer from `vertex_order_new`. `verifying_contract_hex` must
+/// be a valid, NUL-terminated C string. `out_digest` must point to at least 32 writable
+/// bytes. `out_error` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_order_digest(
+    handle: *const VertexOrderHandle,
+    chain_id: u64,
+    verifying_contract_hex: *const c_char,
+    out_digest: *mut u8,
+    out_error: *mut VertexFfiError,
+) {
+    if handle.is_null() || out_digest.is_null() {
+        write_error(out_error, VertexFfiError::NullPointer);
+        return;
+    }
+
+    let result = (|| -> Result<[u8; 32], VertexFfiError> {
+        let verifying_contract = read_address(verifying_contract_hex)?;
+        let order = &(*handle).0;
+        let struct_hash = order
+            .struct_hash()
+            .map_err(|_| VertexFfiError::InvalidInput)?;
+        let domain = vertex_domain(chain_id, verifying_contract);
+        Ok(eip712_digest(domain.separator(), struct_hash))
+    })();
+
+    match result {
+        Ok(digest) => {
+            ptr::copy_nonoverlapping(digest.as_ptr(), out_digest, 32);
+            write_error(out_error, VertexFfiError::Ok);
+        }
+        Err(e) => write_error(out_error, e),
+    }
+}
+
+/// Injects `signature_bytes` (raw, not hex) and serializes the resulting signed order
+/// to a JSON byte buffer. The buffer must be released with `vertex_buffer_free`.
+///
+/// # Safety
+/// `handle` must be a live pointer from `vertex_order_new`. `signature_bytes` must
+/// point to `signature_len` readable bytes. `out_len`/`out_error` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_order_to_signed_json(
+    handle: *const VertexOrderHandle,
+    signature_bytes: *const u8,
+    signature_len: usize,
+    out_len: *mut usize,
+    out_error: *mut VertexFfiError,
+) -> *mut u8 {
+    if handle.is_null() || signature_bytes.is_null() {
+        write_error(out_error, VertexFfiError::NullPointer);
+        return ptr::null_mut();
+    }
+
+    let order = &(*handle).0;
+    let signature = Bytes::from(std::slice::from_raw_parts(signature_bytes, signature_len).to_vec());
+    let signed = order.to_signed_binding(&signature);
+
+    match serde_json::to_vec(&signed) {
+        Ok(json) => {
+            if !out_len.is_null() {
+                *out_len = json.len();
+            }
+            write_error(out_error, VertexFfiError::Ok);
+            // `into_boxed_slice` drops any excess capacity, so the returned pointer's
+            // length is always exactly `len` bytes for `vertex_buffer_free` to
+            // reconstruct without guessing at capacity.
+            Box::into_raw(json.into_boxed_slice()) as *mut u8
+        }
+        Err(_) => {
+            write_error(out_error, VertexFfiError::SerializeFailed);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Opaque handle to a built `Cancellation`.
+pub struct VertexCancellationHandle(Cancellation);
+
+/// `product_ids_len` and `digests_len` must be equal: `Cancellation` pairs one digest
+/// per cancelled product id.
+///
+/// # Safety
+/// `sender_address_hex`, `subaccount_name` must be valid, NUL-terminated C strings.
+/// `product_ids` must point to `product_ids_len` readable `u32`s. `out_error` may be
+/// null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_cancellation_new(
+    sender_address_hex: *const c_char,
+    subaccount_name: *const c_char,
+    product_ids: *const u32,
+    product_ids_len: usize,
+    digests: *const [u8; 32],
+    digests_len: usize,
+    nonce: u64,
+    out_error: *mut VertexFfiError,
+) -> *mut VertexCancellationHandle {
+    let result = (|| -> Result<VertexCancellationHandle, VertexFfiError> {
+        let address = read_address(sender_address_hex)?;
+        let name = read_subaccount_name(subaccount_name)?;
+        if product_ids.is_null() || digests.is_null() {
+            return Err(VertexFfiError::NullPointer);
+        }
+        // `Cancellation` pairs `productIds`/`digests` 1:1 (one digest per cancelled
+        // product id); mismatched lengths would silently misalign them.
+        if product_ids_len != digests_len {
+            return Err(VertexFfiError::InvalidInput);
+        }
+        Ok(VertexCancellationHandle(Cancellation {
+            sender: to_bytes32(address, name),
+            productIds: std::slice::from_raw_parts(product_ids, product_ids_len).to_vec(),
+            digests: std::slice::from_raw_parts(digests, digests_len).to_vec(),
+            nonce,
+        }))
+    })();
+
+    match result {
+        Ok(handle) => {
+            write_error(out_error, VertexFfiError::Ok);
+            Box::into_raw(Box::new(handle))
+        }
+        Err(e) => {
+            write_error(out_error, e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// # Safety
+/// Same contract as `vertex_order_free`.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_cancellation_free(handle: *mut VertexCancellationHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Computes the EIP-712 digest a built cancellation would need signed. See
+/// `vertex_order_digest` for the full contract.
+///
+/// # Safety
+/// `handle` must be a live pointer from `vertex_cancellation_new`. `verifying_contract_hex`
+/// must be a valid, NUL-terminated C string. `out_digest` must point to at least 32
+/// writable bytes. `out_error` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_cancellation_digest(
+    handle: *const VertexCancellationHandle,
+    chain_id: u64,
+    verifying_contract_hex: *const c_char,
+    out_digest: *mut u8,
+    out_error: *mut VertexFfiError,
+) {
+    if handle.is_null() || out_digest.is_null() {
+        write_error(out_error, VertexFfiError::NullPointer);
+        return;
+    }
+
+    let result = (|| -> Result<[u8; 32], VertexFfiError> {
+        let verifying_contract = read_address(verifying_contract_hex)?;
+        let cancellation = &(*handle).0;
+        let struct_hash = cancellation
+            .struct_hash()
+            .map_err(|_| VertexFfiError::InvalidInput)?;
+        let domain = vertex_domain(chain_id, verifying_contract);
+        Ok(eip712_digest(domain.separator(), struct_hash))
+    })();
+
+    match result {
+        Ok(digest) => {
+            ptr::copy_nonoverlapping(digest.as_ptr(), out_digest, 32);
+            write_error(out_error, VertexFfiError::Ok);
+        }
+        Err(e) => write_error(out_error, e),
+    }
+}
+
+/// Injects `signature_bytes` (raw, not hex) and serializes the resulting signed
+/// cancellation to a JSON byte buffer. See `vertex_order_to_signed_json` for the full
+/// contract. The buffer must be released with `vertex_buffer_free`.
+///
+/// # Safety
+/// `handle` must be a live pointer from `vertex_cancellation_new`. `signature_bytes`
+/// must point to `signature_len` readable bytes. `out_len`/`out_error` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_cancellation_to_signed_json(
+    handle: *const VertexCancellationHandle,
+    signature_bytes: *const u8,
+    signature_len: usize,
+    out_len: *mut usize,
+    out_error: *mut VertexFfiError,
+) -> *mut u8 {
+    if handle.is_null() || signature_bytes.is_null() {
+        write_error(out_error, VertexFfiError::NullPointer);
+        return ptr::null_mut();
+    }
+
+    let cancellation = &(*handle).0;
+    let signature = Bytes::from(std::slice::from_raw_parts(signature_bytes, signature_len).to_vec());
+    let signed = cancellation.to_signed_binding(&signature);
+
+    match serde_json::to_vec(&signed) {
+        Ok(json) => {
+            if !out_len.is_null() {
+                *out_len = json.len();
+            }
+            write_error(out_error, VertexFfiError::Ok);
+            Box::into_raw(json.into_boxed_slice()) as *mut u8
+        }
+        Err(_) => {
+            write_error(out_error, VertexFfiError::SerializeFailed);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Opaque handle to a built `WithdrawCollateral`.
+pub struct VertexWithdrawCollateralHandle(WithdrawCollateral);
+
+/// # Safety
+/// `sender_address_hex`, `subaccount_name` must be valid, NUL-terminated C strings.
+/// `out_error` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_withdraw_collateral_new(
+    sender_address_hex: *const c_char,
+    subaccount_name: *const c_char,
+    product_id: u32,
+    amount: u128,
+    nonce: u64,
+    out_error: *mut VertexFfiError,
+) -> *mut VertexWithdrawCollateralHandle {
+    let result = (|| -> Result<VertexWithdrawCollateralHandle, VertexFfiError> {
+        let address = read_address(sender_address_hex)?;
+        let name = read_subaccount_name(subaccount_name)?;
+        Ok(VertexWithdrawCollateralHandle(WithdrawCollateral {
+            sender: to_bytes32(address, name),
+            productId: product_id,
+            amount,
+            nonce,
+        }))
+    })();
+
+    match result {
+        Ok(handle) => {
+            write_error(out_error, VertexFfiError::Ok);
+            Box::into_raw(Box::new(handle))
+        }
+        Err(e) => {
+            write_error(out_error, e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// # Safety
+/// Same contract as `vertex_order_free`.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_withdraw_collateral_free(handle: *mut VertexWithdrawCollateralHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Computes the EIP-712 digest a built withdrawal would need signed. See
+/// `vertex_order_digest` for the full contract.
+///
+/// # Safety
+/// `handle` must be a live pointer from `vertex_withdraw_collateral_new`.
+/// `verifying_contract_hex` must be a valid, NUL-terminated C string. `out_digest` must
+/// point to at least 32 writable bytes. `out_error` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_withdraw_collateral_digest(
+    handle: *const VertexWithdrawCollateralHandle,
+    chain_id: u64,
+    verifying_contract_hex: *const c_char,
+    out_digest: *mut u8,
+    out_error: *mut VertexFfiError,
+) {
+    if handle.is_null() || out_digest.is_null() {
+        write_error(out_error, VertexFfiError::NullPointer);
+        return;
+    }
+
+    let result = (|| -> Result<[u8; 32], VertexFfiError> {
+        let verifying_contract = read_address(verifying_contract_hex)?;
+        let withdrawal = &(*handle).0;
+        let struct_hash = withdrawal
+            .struct_hash()
+            .map_err(|_| VertexFfiError::InvalidInput)?;
+        let domain = vertex_domain(chain_id, verifying_contract);
+        Ok(eip712_digest(domain.separator(), struct_hash))
+    })();
+
+    match result {
+        Ok(digest) => {
+            ptr::copy_nonoverlapping(digest.as_ptr(), out_digest, 32);
+            write_error(out_error, VertexFfiError::Ok);
+        }
+        Err(e) => write_error(out_error, e),
+    }
+}
+
+/// Injects `signature_bytes` (raw, not hex) and serializes the resulting signed
+/// withdrawal to a JSON byte buffer. See `vertex_order_to_signed_json` for the full
+/// contract. The buffer must be released with `vertex_buffer_free`.
+///
+/// # Safety
+/// `handle` must be a live pointer from `vertex_withdraw_collateral_new`.
+/// `signature_bytes` must point to `signature_len` readable bytes. `out_len`/`out_error`
+/// may be null.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_withdraw_collateral_to_signed_json(
+    handle: *const VertexWithdrawCollateralHandle,
+    signature_bytes: *const u8,
+    signature_len: usize,
+    out_len: *mut usize,
+    out_error: *mut VertexFfiError,
+) -> *mut u8 {
+    if handle.is_null() || signature_bytes.is_null() {
+        write_error(out_error, VertexFfiError::NullPointer);
+        return ptr::null_mut();
+    }
+
+    let withdrawal = &(*handle).0;
+    let signature = Bytes::from(std::slice::from_raw_parts(signature_bytes, signature_len).to_vec());
+    // `WithdrawCollateral` has no `to_signed_binding`, so this serializes the
+    // `SignedVertexTx` envelope (`type`, `withdraw_collateral`, `signature`).
+    let signed = crate::wire::VertexTx::WithdrawCollateral(withdrawal.clone()).to_signed(&signature);
+
+    match serde_json::to_vec(&signed) {
+        Ok(json) => {
+            if !out_len.is_null() {
+                *out_len = json.len();
+            }
+            write_error(out_error, VertexFfiError::Ok);
+            Box::into_raw(json.into_boxed_slice()) as *mut u8
+        }
+        Err(_) => {
+            write_error(out_error, VertexFfiError::SerializeFailed);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Frees a buffer returned by one of the `*_to_signed_json` functions.
+///
+/// # Safety
+/// `buf`/`len` must be exactly what a previous call returned (via its return value and
+/// `out_len`), and must not have already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_buffer_free(buf: *mut u8, len: usize) {
+    if !buf.is_null() {
+        drop(Box::from_raw(std::slice::from_raw_parts_mut(buf, len)));
+    }
+}
+
+/// Encodes `address_hex`/`subaccount_name` into the 32-byte `sender` representation
+/// used throughout the crate, writing the result into the 32-byte buffer at
+/// `out_bytes32`.
+///
+/// # Safety
+/// `out_bytes32` must point to at least 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_to_bytes32(
+    address_hex: *const c_char,
+    subaccount_name: *const c_char,
+    out_bytes32: *mut u8,
+    out_error: *mut VertexFfiError,
+) {
+    if out_bytes32.is_null() {
+        write_error(out_error, VertexFfiError::NullPointer);
+        return;
+    }
+    let result = (|| -> Result<[u8; 32], VertexFfiError> {
+        let address = read_address(address_hex)?;
+        let name = read_subaccount_name(subaccount_name)?;
+        Ok(to_bytes32(address, name))
+    })();
+
+    match result {
+        Ok(bytes) => {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), out_bytes32, 32);
+            write_error(out_error, VertexFfiError::Ok);
+        }
+        Err(e) => write_error(out_error, e),
+    }
+}
+
+/// Decodes a 32-byte `sender` back into a `0x`-prefixed address hex string and a
+/// subaccount name string. Both must be released with `vertex_string_free`.
+///
+/// # Safety
+/// `bytes32` must point to 32 readable bytes. `out_address_hex`/`out_name` must be
+/// valid, writable `*mut c_char` out-params.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_from_bytes32(
+    bytes32: *const u8,
+    out_address_hex: *mut *mut c_char,
+    out_name: *mut *mut c_char,
+    out_error: *mut VertexFfiError,
+) {
+    if bytes32.is_null() || out_address_hex.is_null() || out_name.is_null() {
+        write_error(out_error, VertexFfiError::NullPointer);
+        return;
+    }
+    let mut buf = [0u8; 32];
+    ptr::copy_nonoverlapping(bytes32, buf.as_mut_ptr(), 32);
+    // `from_bytes32` panics (via `String::from_utf8(..).unwrap()`) if the name portion
+    // isn't valid UTF-8, which an arbitrary caller-supplied buffer has no guarantee of.
+    // Catch it here rather than let it unwind across the `extern "C"` boundary, which
+    // would be undefined behavior.
+    let (address, name) = match std::panic::catch_unwind(move || from_bytes32(buf)) {
+        Ok(decoded) => decoded,
+        Err(_) => {
+            write_error(out_error, VertexFfiError::PanicAtBoundary);
+            return;
+        }
+    };
+
+    let address_hex = match CString::new(format!("{address:#x}")) {
+        Ok(s) => s,
+        Err(_) => {
+            write_error(out_error, VertexFfiError::InteriorNul);
+            return;
+        }
+    };
+    let name = match CString::new(name.trim_end_matches('\0')) {
+        Ok(s) => s,
+        Err(_) => {
+            write_error(out_error, VertexFfiError::InteriorNul);
+            return;
+        }
+    };
+
+    *out_address_hex = address_hex.into_raw();
+    *out_name = name.into_raw();
+    write_error(out_error, VertexFfiError::Ok);
+}
+
+/// Frees a string returned by `vertex_from_bytes32`.
+///
+/// # Safety
+/// `s` must either be null or a pointer previously returned by this module's `CString`
+/// out-params that hasn't already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vertex_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vertex_utils::verify::VertexVerify;
+    use ethers::signers::{LocalWallet, Signer};
+    use std::str::FromStr;
+
+    /// A fixed private key so the test is deterministic; the address it recovers to
+    /// doesn't matter, only that it round-trips.
+    fn test_wallet() -> LocalWallet {
+        "0000000000000000000000000000000000000000000000000000000000000001"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn order_digest_signs_and_recovers_through_vertex_verify() {
+        let sender_hex = CString::new("0x0000000000000000000000000000000000000001").unwrap();
+        let subaccount_name = CString::new("primary").unwrap();
+        let verifying_contract_hex =
+            CString::new("0x0000000000000000000000000000000000000002").unwrap();
+        let mut error = VertexFfiError::Ok;
+
+        let handle = unsafe {
+            vertex_order_new(
+                sender_hex.as_ptr(),
+                subaccount_name.as_ptr(),
+                1_000_000_000_000_000_000,
+                42,
+                0,
+                1_700_000_000,
+                1_699_999_999,
+                0,
+                false,
+                false,
+                &mut error,
+            )
+        };
+        assert_eq!(error, VertexFfiError::Ok);
+        assert!(!handle.is_null());
+
+        let chain_id = 1u64;
+        let mut digest = [0u8; 32];
+        unsafe {
+            vertex_order_digest(
+                handle,
+                chain_id,
+                verifying_contract_hex.as_ptr(),
+                digest.as_mut_ptr(),
+                &mut error,
+            );
+        }
+        assert_eq!(error, VertexFfiError::Ok);
+
+        let wallet = test_wallet();
+        let signature = wallet.sign_hash(digest.into()).unwrap();
+
+        let order = unsafe { &(*handle).0 };
+        let verifying_contract = H160::from_str("0x0000000000000000000000000000000000000002").unwrap();
+        let recovered = order
+            .recover_signer(chain_id, verifying_contract, &Bytes::from(signature.to_vec()))
+            .unwrap();
+        assert_eq!(recovered, wallet.address());
+
+        unsafe {
+            vertex_order_free(handle);
+        }
+    }
+
+    #[test]
+    fn order_round_trips_build_sign_and_free() {
+        let sender_hex = CString::new("0x0000000000000000000000000000000000000001").unwrap();
+        let subaccount_name = CString::new("primary").unwrap();
+        let mut error = VertexFfiError::Ok;
+
+        let handle = unsafe {
+            vertex_order_new(
+                sender_hex.as_ptr(),
+                subaccount_name.as_ptr(),
+                1_000_000_000_000_000_000,
+                42,
+                0,
+                1_700_000_000,
+                1_699_999_999,
+                0,
+                false,
+                false,
+                &mut error,
+            )
+        };
+        assert_eq!(error, VertexFfiError::Ok);
+        assert!(!handle.is_null());
+
+        let signature = [7u8; 65];
+        let mut out_len: usize = 0;
+        let json_ptr = unsafe {
+            vertex_order_to_signed_json(
+                handle,
+                signature.as_ptr(),
+                signature.len(),
+                &mut out_len,
+                &mut error,
+            )
+        };
+        assert_eq!(error, VertexFfiError::Ok);
+        assert!(!json_ptr.is_null());
+
+        let json_bytes = unsafe { std::slice::from_raw_parts(json_ptr, out_len) };
+        let value: serde_json::Value = serde_json::from_slice(json_bytes).unwrap();
+        assert!(value.get("order").is_some());
+        let signature_hex = value["signature"].as_str().unwrap();
+        assert!(signature_hex.starts_with("0x"));
+        assert_eq!(Bytes::from_str(signature_hex).unwrap(), Bytes::from(signature.to_vec()));
+
+        unsafe {
+            vertex_buffer_free(json_ptr, out_len);
+            vertex_order_free(handle);
+        }
+    }
+
+    #[test]
+    fn cancellation_rejects_mismatched_product_ids_and_digests_length() {
+        let sender_hex = CString::new("0x0000000000000000000000000000000000000001").unwrap();
+        let subaccount_name = CString::new("primary").unwrap();
+        let product_ids = [1u32, 2u32];
+        let digests = [[3u8; 32]];
+        let mut error = VertexFfiError::Ok;
+
+        let handle = unsafe {
+            vertex_cancellation_new(
+                sender_hex.as_ptr(),
+                subaccount_name.as_ptr(),
+                product_ids.as_ptr(),
+                product_ids.len(),
+                digests.as_ptr(),
+                digests.len(),
+                1,
+                &mut error,
+            )
+        };
+
+        assert!(handle.is_null());
+        assert_eq!(error, VertexFfiError::InvalidInput);
+    }
+
+    #[test]
+    fn cancellation_digest_signs_and_serializes_round_trip() {
+        let sender_hex = CString::new("0x0000000000000000000000000000000000000001").unwrap();
+        let subaccount_name = CString::new("primary").unwrap();
+        let verifying_contract_hex =
+            CString::new("0x0000000000000000000000000000000000000002").unwrap();
+        let product_ids = [1u32, 2u32];
+        let digests = [[3u8; 32], [4u8; 32]];
+        let mut error = VertexFfiError::Ok;
+
+        let handle = unsafe {
+            vertex_cancellation_new(
+                sender_hex.as_ptr(),
+                subaccount_name.as_ptr(),
+                product_ids.as_ptr(),
+                product_ids.len(),
+                digests.as_ptr(),
+                digests.len(),
+                1,
+                &mut error,
+            )
+        };
+        assert_eq!(error, VertexFfiError::Ok);
+        assert!(!handle.is_null());
+
+        let chain_id = 1u64;
+        let mut digest = [0u8; 32];
+        unsafe {
+            vertex_cancellation_digest(
+                handle,
+                chain_id,
+                verifying_contract_hex.as_ptr(),
+                digest.as_mut_ptr(),
+                &mut error,
+            );
+        }
+        assert_eq!(error, VertexFfiError::Ok);
+
+        let wallet = test_wallet();
+        let signature = wallet.sign_hash(digest.into()).unwrap();
+
+        let cancellation = unsafe { &(*handle).0 };
+        let verifying_contract = H160::from_str("0x0000000000000000000000000000000000000002").unwrap();
+        let recovered = cancellation
+            .recover_signer(chain_id, verifying_contract, &Bytes::from(signature.to_vec()))
+            .unwrap();
+        assert_eq!(recovered, wallet.address());
+
+        let signature_bytes = signature.to_vec();
+        let mut out_len: usize = 0;
+        let json_ptr = unsafe {
+            vertex_cancellation_to_signed_json(
+                handle,
+                signature_bytes.as_ptr(),
+                signature_bytes.len(),
+                &mut out_len,
+                &mut error,
+            )
+        };
+        assert_eq!(error, VertexFfiError::Ok);
+        assert!(!json_ptr.is_null());
+
+        let json_bytes = unsafe { std::slice::from_raw_parts(json_ptr, out_len) };
+        let value: serde_json::Value = serde_json::from_slice(json_bytes).unwrap();
+        assert!(value.get("cancellation").is_some());
+
+        unsafe {
+            vertex_buffer_free(json_ptr, out_len);
+            vertex_cancellation_free(handle);
+        }
+    }
+
+    #[test]
+    fn withdraw_collateral_digest_signs_and_serializes_round_trip() {
+        let sender_hex = CString::new("0x0000000000000000000000000000000000000001").unwrap();
+        let subaccount_name = CString::new("primary").unwrap();
+        let verifying_contract_hex =
+            CString::new("0x0000000000000000000000000000000000000002").unwrap();
+        let mut error = VertexFfiError::Ok;
+
+        let handle = unsafe {
+            vertex_withdraw_collateral_new(
+                sender_hex.as_ptr(),
+                subaccount_name.as_ptr(),
+                7,
+                1_000_000u128,
+                1,
+                &mut error,
+            )
+        };
+        assert_eq!(error, VertexFfiError::Ok);
+        assert!(!handle.is_null());
+
+        let chain_id = 1u64;
+        let mut digest = [0u8; 32];
+        unsafe {
+            vertex_withdraw_collateral_digest(
+                handle,
+                chain_id,
+                verifying_contract_hex.as_ptr(),
+                digest.as_mut_ptr(),
+                &mut error,
+            );
+        }
+        assert_eq!(error, VertexFfiError::Ok);
+
+        let wallet = test_wallet();
+        let signature = wallet.sign_hash(digest.into()).unwrap();
+
+        let withdrawal = unsafe { &(*handle).0 };
+        let verifying_contract = H160::from_str("0x0000000000000000000000000000000000000002").unwrap();
+        let recovered = withdrawal
+            .recover_signer(chain_id, verifying_contract, &Bytes::from(signature.to_vec()))
+            .unwrap();
+        assert_eq!(recovered, wallet.address());
+
+        let signature_bytes = signature.to_vec();
+        let mut out_len: usize = 0;
+        let json_ptr = unsafe {
+            vertex_withdraw_collateral_to_signed_json(
+                handle,
+                signature_bytes.as_ptr(),
+                signature_bytes.len(),
+                &mut out_len,
+                &mut error,
+            )
+        };
+        assert_eq!(error, VertexFfiError::Ok);
+        assert!(!json_ptr.is_null());
+
+        let json_bytes = unsafe { std::slice::from_raw_parts(json_ptr, out_len) };
+        let value: serde_json::Value = serde_json::from_slice(json_bytes).unwrap();
+        assert!(value.get("withdraw_collateral").is_some());
+
+        unsafe {
+            vertex_buffer_free(json_ptr, out_len);
+            vertex_withdraw_collateral_free(handle);
+        }
+    }
+
+    #[test]
+    fn bytes32_round_trips_through_ffi_and_panics_at_boundary_are_caught() {
+        let address_hex = CString::new("0x0000000000000000000000000000000000000001").unwrap();
+        let subaccount_name = CString::new("primary").unwrap();
+        let mut bytes32 = [0u8; 32];
+        let mut error = VertexFfiError::Ok;
+
+        unsafe {
+            vertex_to_bytes32(
+                address_hex.as_ptr(),
+                subaccount_name.as_ptr(),
+                bytes32.as_mut_ptr(),
+                &mut error,
+            );
+        }
+        assert_eq!(error, VertexFfiError::Ok);
+
+        let mut out_address_hex: *mut c_char = ptr::null_mut();
+        let mut out_name: *mut c_char = ptr::null_mut();
+        unsafe {
+            vertex_from_bytes32(
+                bytes32.as_ptr(),
+                &mut out_address_hex,
+                &mut out_name,
+                &mut error,
+            );
+        }
+        assert_eq!(error, VertexFfiError::Ok);
+        assert!(!out_address_hex.is_null());
+        assert!(!out_name.is_null());
+
+        let decoded_name = unsafe { CStr::from_ptr(out_name) }.to_str().unwrap();
+        assert_eq!(decoded_name, "primary");
+
+        unsafe {
+            vertex_string_free(out_address_hex);
+            vertex_string_free(out_name);
+        }
+
+        // A name portion that isn't valid UTF-8 makes `from_bytes32` panic; that panic
+        // must come back as `PanicAtBoundary` rather than unwind across `extern "C"`.
+        let mut invalid_utf8 = [0u8; 32];
+        invalid_utf8[20..].copy_from_slice(&[0xffu8; 12]);
+        let mut bad_address_hex: *mut c_char = ptr::null_mut();
+        let mut bad_name: *mut c_char = ptr::null_mut();
+        unsafe {
+            vertex_from_bytes32(
+                invalid_utf8.as_ptr(),
+                &mut bad_address_hex,
+                &mut bad_name,
+                &mut error,
+            );
+        }
+        assert_eq!(error, VertexFfiError::PanicAtBoundary);
+        assert!(bad_address_hex.is_null());
+        assert!(bad_name.is_null());
+    }
+}
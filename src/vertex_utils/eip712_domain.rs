@@ -0,0 +1,33 @@
+//! Shared EIP-712 domain/digest construction for the Vertex signing and verification paths.
+//!
+//! The `#[eip712()]` attribute on the structs in `eip712_structs` is left empty, so the
+//! derive doesn't bake in a chain id or verifying contract. `signer` and `verify` both
+//! need to build the same domain and combine it with a struct hash the same way, so that
+//! logic lives here instead of being duplicated.
+
+use ethers::types::transaction::eip712::EIP712Domain;
+use ethers::types::H160;
+use ethers::utils::keccak256;
+
+const VERTEX_DOMAIN_NAME: &str = "Vertex";
+const VERTEX_DOMAIN_VERSION: &str = "0.0.1";
+
+pub(crate) fn vertex_domain(chain_id: u64, verifying_contract: H160) -> EIP712Domain {
+    EIP712Domain {
+        name: Some(VERTEX_DOMAIN_NAME.to_string()),
+        version: Some(VERTEX_DOMAIN_VERSION.to_string()),
+        chain_id: Some(chain_id.into()),
+        verifying_contract: Some(verifying_contract),
+        salt: None,
+    }
+}
+
+/// `keccak256(0x1901 || domain_separator || struct_hash)`, i.e. the final digest an
+/// EIP-712 signer signs.
+pub(crate) fn eip712_digest(domain_separator: [u8; 32], struct_hash: [u8; 32]) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(2 + 32 + 32);
+    bytes.extend_from_slice(&[0x19, 0x01]);
+    bytes.extend_from_slice(&domain_separator);
+    bytes.extend_from_slice(&struct_hash);
+    keccak256(bytes)
+}
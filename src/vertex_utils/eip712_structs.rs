@@ -6,6 +6,7 @@ use crate::serialize_utils::{
     deserialize_vec_bytes32, serialize_bytes32, serialize_i128, serialize_u128, serialize_u64,
     serialize_vec_bytes32,
 };
+use crate::vertex_utils::signer::{sign_eip712_tx, VertexSigner};
 use ethers::prelude::*;
 use ethers::types::transaction::eip712::Eip712;
 use ethers_derive_eip712::*;
@@ -130,6 +131,16 @@ impl Order {
         }
     }
 
+    pub async fn sign(
+        &self,
+        signer: &impl VertexSigner,
+        chain_id: u64,
+        verifying_contract: H160,
+    ) -> Result<endpoint::SignedOrder> {
+        let signature = sign_eip712_tx(self, signer, chain_id, verifying_contract).await?;
+        Ok(self.to_signed_binding(&signature))
+    }
+
     pub fn to_offchain_book_signed_binding(&self, signature: &Bytes) -> offchain_book::SignedOrder {
         offchain_book::SignedOrder {
             order: self.to_offchain_book_binding(),
@@ -224,6 +235,16 @@ impl Cancellation {
         }
     }
 
+    pub async fn sign(
+        &self,
+        signer: &impl VertexSigner,
+        chain_id: u64,
+        verifying_contract: H160,
+    ) -> Result<endpoint::SignedCancellation> {
+        let signature = sign_eip712_tx(self, signer, chain_id, verifying_contract).await?;
+        Ok(self.to_signed_binding(&signature))
+    }
+
     pub fn recv_time(&self) -> u64 {
         self.nonce >> 20
     }
@@ -270,6 +291,16 @@ impl CancellationProducts {
         }
     }
 
+    pub async fn sign(
+        &self,
+        signer: &impl VertexSigner,
+        chain_id: u64,
+        verifying_contract: H160,
+    ) -> Result<endpoint::SignedCancellationProducts> {
+        let signature = sign_eip712_tx(self, signer, chain_id, verifying_contract).await?;
+        Ok(self.to_signed_binding(&signature))
+    }
+
     pub fn recv_time(&self) -> u64 {
         self.nonce >> 20
     }
@@ -320,6 +351,16 @@ impl LinkSigner {
         }
     }
 
+    pub async fn sign(
+        &self,
+        signer: &impl VertexSigner,
+        chain_id: u64,
+        verifying_contract: H160,
+    ) -> Result<endpoint::SignedLinkSigner> {
+        let signature = sign_eip712_tx(self, signer, chain_id, verifying_contract).await?;
+        Ok(self.to_signed_binding(&signature))
+    }
+
     pub fn recv_time(&self) -> u64 {
         self.nonce >> 20
     }
@@ -374,6 +415,15 @@ impl LiquidateSubaccount {
             nonce: self.nonce,
         }
     }
+
+    pub async fn sign(
+        &self,
+        signer: &impl VertexSigner,
+        chain_id: u64,
+        verifying_contract: H160,
+    ) -> Result<Bytes> {
+        sign_eip712_tx(self, signer, chain_id, verifying_contract).await
+    }
 }
 
 #[derive(
@@ -417,6 +467,15 @@ impl WithdrawCollateral {
             nonce: self.nonce,
         }
     }
+
+    pub async fn sign(
+        &self,
+        signer: &impl VertexSigner,
+        chain_id: u64,
+        verifying_contract: H160,
+    ) -> Result<Bytes> {
+        sign_eip712_tx(self, signer, chain_id, verifying_contract).await
+    }
 }
 
 #[derive(
@@ -474,6 +533,15 @@ impl MintLp {
             nonce: self.nonce,
         }
     }
+
+    pub async fn sign(
+        &self,
+        signer: &impl VertexSigner,
+        chain_id: u64,
+        verifying_contract: H160,
+    ) -> Result<Bytes> {
+        sign_eip712_tx(self, signer, chain_id, verifying_contract).await
+    }
 }
 
 #[derive(
@@ -517,6 +585,15 @@ impl BurnLp {
             nonce: self.nonce,
         }
     }
+
+    pub async fn sign(
+        &self,
+        signer: &impl VertexSigner,
+        chain_id: u64,
+        verifying_contract: H160,
+    ) -> Result<Bytes> {
+        sign_eip712_tx(self, signer, chain_id, verifying_contract).await
+    }
 }
 
 #[derive(
@@ -0,0 +1,190 @@
+//! Safe construction of [`Order`], which packs `expiration` and `nonce` with a few
+//! bit-shifted flags (see the comment on `Order` itself). `OrderBuilder` is the one
+//! place that does the packing so callers never hand-assemble those `u64`s themselves.
+
+use crate::bindings::endpoint;
+use crate::vertex_utils::eip712_structs::{Order, OrderType};
+use eyre::Result;
+
+const EXPIRATION_BITS: u32 = 58;
+const RECV_TIME_BITS: u32 = 44;
+const NONCE_RAND_BITS: u32 = 20;
+const REDUCE_ONLY_BIT: u64 = 1 << 61;
+const TRIGGER_BIT: u64 = 1 << 63;
+
+pub struct OrderBuilder {
+    sender: [u8; 32],
+    price_x18: i128,
+    amount: i128,
+    order_type: OrderType,
+    expiration: u64,
+    reduce_only: bool,
+    recv_time: u64,
+    nonce_rand: u64,
+    is_trigger: bool,
+}
+
+impl OrderBuilder {
+    pub fn new(sender: [u8; 32], price_x18: i128, amount: i128) -> Self {
+        Self {
+            sender,
+            price_x18,
+            amount,
+            order_type: OrderType::Default,
+            expiration: 0,
+            reduce_only: false,
+            recv_time: 0,
+            nonce_rand: 0,
+            is_trigger: false,
+        }
+    }
+
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
+
+    pub fn expiration(mut self, expiration: u64) -> Self {
+        self.expiration = expiration;
+        self
+    }
+
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+
+    pub fn recv_time(mut self, recv_time: u64) -> Self {
+        self.recv_time = recv_time;
+        self
+    }
+
+    /// The low 20 bits of `nonce` that aren't part of `recv_time`. Defaults to 0;
+    /// override if the caller wants a distinguishing value (e.g. a per-request counter).
+    pub fn nonce_rand(mut self, nonce_rand: u64) -> Self {
+        self.nonce_rand = nonce_rand;
+        self
+    }
+
+    pub fn is_trigger(mut self, is_trigger: bool) -> Self {
+        self.is_trigger = is_trigger;
+        self
+    }
+
+    pub fn build(self) -> Result<Order> {
+        if self.expiration >= (1u64 << EXPIRATION_BITS) {
+            return Err(eyre::eyre!(
+                "expiration {} does not fit in {EXPIRATION_BITS} bits",
+                self.expiration
+            ));
+        }
+        if self.recv_time >= (1u64 << RECV_TIME_BITS) {
+            return Err(eyre::eyre!(
+                "recv_time {} does not fit in {RECV_TIME_BITS} bits",
+                self.recv_time
+            ));
+        }
+        // `recv_time`'s own top bit lands on nonce bit 63 once shifted into place — the
+        // same bit `is_trigger_order()` reads as the trigger flag. Without this check, a
+        // `recv_time >= 1 << (RECV_TIME_BITS - 1)` would silently flip that flag on for a
+        // caller who never asked for a trigger order.
+        if !self.is_trigger && self.recv_time >= (1u64 << (RECV_TIME_BITS - 1)) {
+            return Err(eyre::eyre!(
+                "recv_time {} would set the trigger bit (nonce bit 63) but is_trigger is false",
+                self.recv_time
+            ));
+        }
+        if self.nonce_rand >= (1u64 << NONCE_RAND_BITS) {
+            return Err(eyre::eyre!(
+                "nonce_rand {} does not fit in {NONCE_RAND_BITS} bits",
+                self.nonce_rand
+            ));
+        }
+
+        let mut expiration = self.order_type.apply_to_expiration(self.expiration);
+        if self.reduce_only {
+            expiration |= REDUCE_ONLY_BIT;
+        }
+
+        let mut nonce = (self.recv_time << NONCE_RAND_BITS) | self.nonce_rand;
+        if self.is_trigger {
+            nonce |= TRIGGER_BIT;
+        }
+
+        // `Order`'s `expiration`/`nonce` fields are private to `eip712_structs`, so go
+        // through `from_binding`, which copies them over unchanged.
+        Ok(Order::from_binding(&endpoint::Order {
+            sender: self.sender,
+            price_x18: self.price_x18,
+            amount: self.amount,
+            expiration,
+            nonce,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_accessor() {
+        let order = OrderBuilder::new([7u8; 32], 1_500_000_000_000_000_000, -42)
+            .order_type(OrderType::PostOnly)
+            .expiration(1_700_000_000)
+            .reduce_only(true)
+            .recv_time(1_699_999_999)
+            .is_trigger(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(order.expiration(), 1_700_000_000);
+        assert!(order.reduce_only());
+        assert_eq!(order.reserved_bits(), 0);
+        assert_eq!(order.recv_time(), 1_699_999_999);
+        assert!(!order.is_trigger_order());
+    }
+
+    #[test]
+    fn trigger_bit_round_trips() {
+        // `is_trigger_order()` reads the same nonce bit that `recv_time()` treats as its
+        // own top bit, so a triggered order's `recv_time()` reflects that bit being set.
+        let recv_time = 12_345;
+        let order = OrderBuilder::new([1u8; 32], 0, 0)
+            .recv_time(recv_time)
+            .is_trigger(true)
+            .build()
+            .unwrap();
+
+        assert!(order.is_trigger_order());
+        assert_eq!(order.recv_time(), recv_time | (1 << 43));
+    }
+
+    #[test]
+    fn rejects_oversized_expiration() {
+        let result = OrderBuilder::new([0u8; 32], 0, 0)
+            .expiration(1 << EXPIRATION_BITS)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_recv_time() {
+        let result = OrderBuilder::new([0u8; 32], 0, 0)
+            .recv_time(1 << RECV_TIME_BITS)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_recv_time_that_would_silently_set_the_trigger_bit() {
+        // `1 << 43` is recv_time's own top bit, which lands on nonce bit 63 — the trigger
+        // flag — once shifted. Without the explicit check, this would build an order
+        // whose `is_trigger_order()` reads `true` despite `is_trigger(false)`.
+        let result = OrderBuilder::new([0u8; 32], 0, 0)
+            .recv_time(1u64 << (RECV_TIME_BITS - 1))
+            .is_trigger(false)
+            .build();
+        assert!(result.is_err());
+    }
+}
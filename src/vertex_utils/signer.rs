@@ -0,0 +1,58 @@
+//! Pluggable EIP-712 signing.
+//!
+//! `to_signed_binding` on the request structs assumes the caller already has a
+//! signature in hand, which is fine for a local private key but leaves no room for a
+//! custody/MPC backend where signing is an async round-trip to a vault. `VertexSigner`
+//! is the seam: anything that can turn a domain + struct hash into a signature can sign
+//! Vertex requests, whether that's a local wallet or a remote service polled for the
+//! result.
+
+use crate::vertex_utils::eip712_domain::{eip712_digest, vertex_domain};
+use async_trait::async_trait;
+use ethers::signers::LocalWallet;
+use ethers::types::transaction::eip712::{EIP712Domain, Eip712};
+use ethers::types::{Bytes, H160};
+use eyre::Result;
+
+#[async_trait]
+pub trait VertexSigner: Send + Sync {
+    async fn sign_eip712(&self, domain: &EIP712Domain, struct_hash: [u8; 32]) -> Result<Bytes>;
+}
+
+// `ethers::signers::Signer` itself doesn't expose a "sign this raw digest" method: only
+// `sign_message` (which EIP-191-prefixes first) and `sign_typed_data` (which re-derives
+// the domain/struct hash from an `Eip712` payload rather than taking the digest this
+// crate already built). `sign_hash` is an inherent method on the concrete `LocalWallet`,
+// so the local-key adapter is impl'd there directly instead of as a blanket impl over
+// `Signer`, which would not compile for an arbitrary `S: Signer`.
+#[async_trait]
+impl VertexSigner for LocalWallet {
+    async fn sign_eip712(&self, domain: &EIP712Domain, struct_hash: [u8; 32]) -> Result<Bytes> {
+        let digest = eip712_digest(domain.separator(), struct_hash);
+        let signature = self
+            .sign_hash(digest.into())
+            .map_err(|e| eyre::eyre!("failed to sign eip712 digest: {e}"))?;
+        Ok(Bytes::from(signature.to_vec()))
+    }
+}
+
+/// Signs `tx` with `signer` against the Vertex domain for `chain_id`/`verifying_contract`.
+///
+/// Shared by the `sign` convenience methods on the request structs. Those with a
+/// `to_signed_binding` wrap the signature in it; the rest return the raw signature.
+pub(crate) async fn sign_eip712_tx<T>(
+    tx: &T,
+    signer: &impl VertexSigner,
+    chain_id: u64,
+    verifying_contract: H160,
+) -> Result<Bytes>
+where
+    T: Eip712,
+    T::Error: std::fmt::Display,
+{
+    let struct_hash = tx
+        .struct_hash()
+        .map_err(|e| eyre::eyre!("failed to hash eip712 struct: {e}"))?;
+    let domain = vertex_domain(chain_id, verifying_contract);
+    signer.sign_eip712(&domain, struct_hash).await
+}
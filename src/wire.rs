@@ -0,0 +1,273 @@
+//! Unified representation of the signable requests the execute endpoint accepts.
+//!
+//! The individual `vertex_utils::eip712_structs` types are what you sign, but a client
+//! consuming a mixed stream of execute payloads (logs, a websocket feed, a replay file)
+//! wants one type to decode into and match on. `VertexTx`/`SignedVertexTx` wrap every
+//! request variant behind a single internally-tagged enum that mirrors the protocol's
+//! `{"type": "order", ...}` JSON envelope.
+
+use crate::vertex_utils::eip712_structs::{
+    BurnLp, Cancellation, CancellationProducts, LinkSigner, ListTriggerOrders,
+    LiquidateSubaccount, MintLp, Order, WithdrawCollateral,
+};
+use ethers::types::Bytes;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum VertexTx {
+    #[serde(rename = "order")]
+    Order(Order),
+    #[serde(rename = "cancellation")]
+    Cancellation(Cancellation),
+    #[serde(rename = "cancellation_products")]
+    CancellationProducts(CancellationProducts),
+    #[serde(rename = "link_signer")]
+    LinkSigner(LinkSigner),
+    #[serde(rename = "liquidate_subaccount")]
+    LiquidateSubaccount(LiquidateSubaccount),
+    #[serde(rename = "withdraw_collateral")]
+    WithdrawCollateral(WithdrawCollateral),
+    #[serde(rename = "mint_lp")]
+    MintLp(MintLp),
+    #[serde(rename = "burn_lp")]
+    BurnLp(BurnLp),
+    #[serde(rename = "list_trigger_orders")]
+    ListTriggerOrders(ListTriggerOrders),
+}
+
+/// Mirrors the field names of the corresponding `endpoint::Signed*` binding for each
+/// variant (e.g. `endpoint::SignedOrder { order, signature }`), not a uniform `tx` field,
+/// so a real signed payload off the wire deserializes here and a re-encoded
+/// `SignedVertexTx` matches what the endpoint expects byte-for-byte. `LinkSigner` and
+/// `ListTriggerOrders` keep `tx` because that's what their own `endpoint::Signed*`
+/// bindings actually call the field (confirmed against `SignedLinkSigner`/
+/// `SignedListTriggerOrders` in `eip712_structs`). `Order` and `Cancellation` are
+/// likewise confirmed against `endpoint::SignedOrder`/`SignedCancellation`, which this
+/// crate already constructs via `to_signed_binding`. `LiquidateSubaccount`,
+/// `WithdrawCollateral`, `MintLp`, and `BurnLp` have no `to_signed_binding()` anywhere in
+/// this crate to check their field name against (their `sign()` returns a raw `Bytes`),
+/// so those four variants are `#[serde(skip_deserializing)]`: they still exist for
+/// `VertexTx::to_signed` to construct and serialize outgoing requests, but this crate
+/// refuses to *decode* them off the wire under an unconfirmed field name rather than risk
+/// silently misparsing (or worse, silently "succeeding" on) a real execute payload. Lift
+/// the `skip_deserializing` once each field name is confirmed against the real
+/// `endpoint::Signed*` binding.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SignedVertexTx {
+    #[serde(rename = "order")]
+    Order { order: Order, signature: Bytes },
+    #[serde(rename = "cancellation")]
+    Cancellation {
+        cancellation: Cancellation,
+        signature: Bytes,
+    },
+    #[serde(rename = "cancellation_products")]
+    CancellationProducts {
+        cancellation_products: CancellationProducts,
+        signature: Bytes,
+    },
+    #[serde(rename = "link_signer")]
+    LinkSigner { tx: LinkSigner, signature: Bytes },
+    #[serde(rename = "liquidate_subaccount", skip_deserializing)]
+    LiquidateSubaccount {
+        liquidate_subaccount: LiquidateSubaccount,
+        signature: Bytes,
+    },
+    #[serde(rename = "withdraw_collateral", skip_deserializing)]
+    WithdrawCollateral {
+        withdraw_collateral: WithdrawCollateral,
+        signature: Bytes,
+    },
+    #[serde(rename = "mint_lp", skip_deserializing)]
+    MintLp { mint_lp: MintLp, signature: Bytes },
+    #[serde(rename = "burn_lp", skip_deserializing)]
+    BurnLp { burn_lp: BurnLp, signature: Bytes },
+    #[serde(rename = "list_trigger_orders")]
+    ListTriggerOrders {
+        tx: ListTriggerOrders,
+        signature: Bytes,
+    },
+}
+
+impl VertexTx {
+    pub fn to_signed(self, signature: &Bytes) -> SignedVertexTx {
+        let signature = signature.clone();
+        match self {
+            VertexTx::Order(order) => SignedVertexTx::Order { order, signature },
+            VertexTx::Cancellation(cancellation) => SignedVertexTx::Cancellation {
+                cancellation,
+                signature,
+            },
+            VertexTx::CancellationProducts(cancellation_products) => {
+                SignedVertexTx::CancellationProducts {
+                    cancellation_products,
+                    signature,
+                }
+            }
+            VertexTx::LinkSigner(tx) => SignedVertexTx::LinkSigner { tx, signature },
+            VertexTx::LiquidateSubaccount(liquidate_subaccount) => {
+                SignedVertexTx::LiquidateSubaccount {
+                    liquidate_subaccount,
+                    signature,
+                }
+            }
+            VertexTx::WithdrawCollateral(withdraw_collateral) => {
+                SignedVertexTx::WithdrawCollateral {
+                    withdraw_collateral,
+                    signature,
+                }
+            }
+            VertexTx::MintLp(mint_lp) => SignedVertexTx::MintLp { mint_lp, signature },
+            VertexTx::BurnLp(burn_lp) => SignedVertexTx::BurnLp { burn_lp, signature },
+            VertexTx::ListTriggerOrders(tx) => SignedVertexTx::ListTriggerOrders { tx, signature },
+        }
+    }
+
+    /// The raw nonce (or, for `ListTriggerOrders`, the `recvTime` shifted into nonce
+    /// position) backing this request, for callers that want to compare ordering
+    /// across variants without matching on the enum themselves.
+    pub fn nonce(&self) -> u64 {
+        match self {
+            VertexTx::Order(tx) => tx.raw_nonce(),
+            VertexTx::Cancellation(tx) => tx.nonce,
+            VertexTx::CancellationProducts(tx) => tx.nonce,
+            VertexTx::LinkSigner(tx) => tx.nonce,
+            VertexTx::LiquidateSubaccount(tx) => tx.nonce,
+            VertexTx::WithdrawCollateral(tx) => tx.nonce,
+            VertexTx::MintLp(tx) => tx.nonce,
+            VertexTx::BurnLp(tx) => tx.nonce,
+            VertexTx::ListTriggerOrders(tx) => tx.recvTime << 20,
+        }
+    }
+
+    pub fn recv_time(&self) -> u64 {
+        match self {
+            VertexTx::Order(tx) => tx.recv_time(),
+            VertexTx::Cancellation(tx) => tx.recv_time(),
+            VertexTx::CancellationProducts(tx) => tx.recv_time(),
+            VertexTx::LinkSigner(tx) => tx.recv_time(),
+            VertexTx::LiquidateSubaccount(tx) => tx.nonce >> 20,
+            VertexTx::WithdrawCollateral(tx) => tx.nonce >> 20,
+            VertexTx::MintLp(tx) => tx.nonce >> 20,
+            VertexTx::BurnLp(tx) => tx.nonce >> 20,
+            VertexTx::ListTriggerOrders(tx) => tx.recvTime,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `to_signed_binding` is what actually hits the wire, so a real signed `order`
+    /// envelope is whatever `serde_json::to_value` produces from it plus the `"type"`
+    /// tag. If `SignedVertexTx::Order`'s field name drifted from `endpoint::SignedOrder`'s
+    /// `order` field, this would fail to deserialize.
+    #[test]
+    fn signed_order_round_trips_through_to_signed_binding_shape() {
+        let order = Order::default();
+        let signature = Bytes::from(vec![0u8; 65]);
+        let binding = order.to_signed_binding(&signature);
+
+        let mut envelope = serde_json::to_value(&binding).unwrap();
+        envelope["type"] = serde_json::Value::String("order".to_string());
+
+        let signed_tx: SignedVertexTx = serde_json::from_value(envelope).unwrap();
+        match signed_tx {
+            SignedVertexTx::Order {
+                order: decoded,
+                signature: decoded_signature,
+            } => {
+                assert_eq!(decoded.raw_nonce(), order.raw_nonce());
+                assert_eq!(decoded_signature, signature);
+            }
+            other => panic!("expected SignedVertexTx::Order, got {other:?}"),
+        }
+    }
+
+    /// Same shape check for a type whose `endpoint::Signed*` binding field isn't called
+    /// `order` (it's `cancellation`), to make sure the per-variant rename is actually
+    /// per-variant and not just a coincidence of the first one.
+    #[test]
+    fn signed_cancellation_round_trips_through_to_signed_binding_shape() {
+        let cancellation = Cancellation {
+            sender: [1u8; 32],
+            productIds: vec![1, 2],
+            digests: vec![[2u8; 32], [3u8; 32]],
+            nonce: 7,
+        };
+        let signature = Bytes::from(vec![1u8; 65]);
+        let binding = cancellation.to_signed_binding(&signature);
+
+        let mut envelope = serde_json::to_value(&binding).unwrap();
+        envelope["type"] = serde_json::Value::String("cancellation".to_string());
+
+        let signed_tx: SignedVertexTx = serde_json::from_value(envelope).unwrap();
+        match signed_tx {
+            SignedVertexTx::Cancellation {
+                cancellation: decoded,
+                signature: decoded_signature,
+            } => {
+                assert_eq!(decoded.nonce, cancellation.nonce);
+                assert_eq!(decoded_signature, signature);
+            }
+            other => panic!("expected SignedVertexTx::Cancellation, got {other:?}"),
+        }
+    }
+
+    /// `LiquidateSubaccount` has no `to_signed_binding()`/`endpoint::SignedLiquidateSubaccount`
+    /// in this crate to check the `liquidate_subaccount` field name against, so
+    /// `SignedVertexTx::LiquidateSubaccount` is `#[serde(skip_deserializing)]`: a real
+    /// `liquidate_subaccount` envelope off the wire must be rejected instead of silently
+    /// parsed under a guessed field name.
+    #[test]
+    fn signed_liquidate_subaccount_is_rejected_on_deserialize() {
+        let liquidate_subaccount = LiquidateSubaccount {
+            sender: [1u8; 32],
+            liquidatee: [2u8; 32],
+            mode: 0,
+            healthGroup: 0,
+            amount: 100,
+            nonce: 9,
+        };
+        let signature = Bytes::from(vec![2u8; 65]);
+        let envelope = serde_json::json!({
+            "type": "liquidate_subaccount",
+            "liquidate_subaccount": &liquidate_subaccount,
+            "signature": &signature,
+        });
+
+        let result: Result<SignedVertexTx, _> = serde_json::from_value(envelope);
+        assert!(
+            result.is_err(),
+            "liquidate_subaccount must not deserialize until its field name is confirmed \
+             against the real endpoint::Signed* binding"
+        );
+    }
+
+    /// `VertexTx::to_signed` must still be able to construct and serialize an outgoing
+    /// `liquidate_subaccount` request even though decoding one is refused; `skip_deserializing`
+    /// only disables the `Deserialize` side.
+    #[test]
+    fn signed_liquidate_subaccount_still_serializes_for_outgoing_requests() {
+        let liquidate_subaccount = LiquidateSubaccount {
+            sender: [1u8; 32],
+            liquidatee: [2u8; 32],
+            mode: 0,
+            healthGroup: 0,
+            amount: 100,
+            nonce: 9,
+        };
+        let signed_tx = VertexTx::LiquidateSubaccount(liquidate_subaccount.clone())
+            .to_signed(&Bytes::from(vec![2u8; 65]));
+
+        let envelope = serde_json::to_value(&signed_tx).unwrap();
+        assert_eq!(envelope["type"], "liquidate_subaccount");
+        assert_eq!(
+            envelope["liquidate_subaccount"],
+            serde_json::to_value(&liquidate_subaccount).unwrap()
+        );
+    }
+}